@@ -65,9 +65,15 @@ const (
 	pluginPath = "plugin.wasm"
 
 	// 预测去抖动时间 - 用户停止输入多久后触发AI预测
-	predictionDebounce = 500 * time.Millisecond
+	predictionDebounce = 250 * time.Millisecond
 )
 
+// pluginFilterExports 可用 :wasm 调用的插件导出函数
+// 这些函数接收整个缓冲区并返回处理后的完整缓冲区
+var pluginFilterExports = map[string]bool{
+	"process_command": true,
+}
+
 // translations 多语言翻译字典
 var translations = map[string]map[string]string{
 	LangEN: {
@@ -1438,8 +1444,16 @@ func (m Model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 		// 更新最后输入时间
 		m.lastInputTime = time.Now()
 		// 清除当前建议，标记为等待新的建议
+		suggestion := m.suggestion
 		m.suggestion = ""
 		m.suggestionPending = true
+
+		// 插入模式下 Tab 接受 AI 建议 (Ghost Text)，其它按键直接丢弃建议
+		if m.mode == InsertMode && m.focus == FocusEditor && msg.Type == tea.KeyTab && suggestion != "" && !m.showCompletion {
+			curTab := m.tabs[m.activeTab]
+			m.acceptSuggestion(curTab.Panes[curTab.ActivePane], suggestion)
+			return m, startPredictionDebounce()
+		}
 		
 
 		// 处理按键
@@ -1781,8 +1795,7 @@ func (m Model) handleNormalMode(msg tea.KeyMsg) (tea.Model, tea.Cmd) {
 
 	case "tab":
 		// 触发 WASM 插件处理
-		// Refactor needed: m.callPlugin() -> m.callPlugin(currPane)
-		m.callPlugin(currPane)
+		m.callPlugin(currPane, "process_command")
 	
 	case "p":
 		// 粘贴 (从系统剪贴板)
@@ -2366,6 +2379,25 @@ func (m *Model) executeCommand(cmdStr string) tea.Cmd {
 		return nil
 	}
 
+	// ---------------------------------------------------------
+	// WASM 插件命令 (:wasm [export])
+	// ---------------------------------------------------------
+	if cmd == "wasm" || strings.HasPrefix(cmd, "wasm ") {
+		args := strings.Fields(cmd)
+		export := "process_command"
+		if len(args) > 1 {
+			export = args[1]
+		}
+		// 只允许返回完整缓冲区的 Filter 导出 (编辑器没有撤销)
+		if !pluginFilterExports[export] {
+			m.statusMsg = fmt.Sprintf("⚠ Plugin export %s is not a buffer filter", export)
+			return nil
+		}
+		curTab := m.tabs[m.activeTab]
+		m.callPlugin(curTab.Panes[curTab.ActivePane], export)
+		return nil
+	}
+
 	// ---------------------------------------------------------
 	// Tab Commands (:tabnew)
 	// ---------------------------------------------------------
@@ -3132,8 +3164,8 @@ func (m *Model) deleteCharBackward(p *EditorPane) {
 	}
 }
 
-// callPlugin 调用 WASM 插件处理当前缓冲区
-func (m *Model) callPlugin(p *EditorPane) {
+// callPlugin 调用 WASM 插件的指定导出函数处理当前缓冲区
+func (m *Model) callPlugin(p *EditorPane, export string) {
 	// 检查插件是否可用
 	if m.pluginError != nil {
 		m.statusMsg = fmt.Sprintf("⚠ 插件错误: %v", m.pluginError)
@@ -3149,7 +3181,7 @@ func (m *Model) callPlugin(p *EditorPane) {
 	bufferContent := strings.Join(p.Lines, "\n")
 
 	// 2. 调用 WASM 函数
-	exitCode, output, err := m.plugin.Call("process_command", []byte(bufferContent))
+	exitCode, output, err := m.plugin.Call(export, []byte(bufferContent))
 	if err != nil {
 		m.statusMsg = fmt.Sprintf("⚠ Plugin call failed: %v", err)
 		return
@@ -3164,6 +3196,12 @@ func (m *Model) callPlugin(p *EditorPane) {
 	// 如果插件只返回修改的部分，这里需要更复杂的逻辑
 	// 目前假设它是 "Filter" 模式 (Stdin -> Stdout)
 	newContent := string(output)
+
+	// 输出为空时拒绝替换 (编辑器没有撤销，清空缓冲区无法恢复)
+	if newContent == "" && bufferContent != "" {
+		m.statusMsg = fmt.Sprintf("⚠ Plugin %s returned empty output, buffer unchanged", export)
+		return
+	}
 	
 	// 简单替换整个 buffer
 	p.Lines = strings.Split(newContent, "\n")
@@ -3179,7 +3217,7 @@ func (m *Model) callPlugin(p *EditorPane) {
 		p.CursorX = lineLen
 	}
 
-	m.statusMsg = "✓ Plugin processed buffer"
+	m.statusMsg = fmt.Sprintf("✓ Plugin processed buffer (%s)", export)
 }
 
 // pasteToPane 在当前光标位置粘贴文本 (支持多行)
@@ -3441,27 +3479,41 @@ func (m *Model) predictCode() {
 	if m.pluginError != nil || m.plugin == nil {
 		return
 	}
+	// 焦点不在编辑器 (文件树/Git 面板) 时不预测，避免隐藏的建议被 Tab 误插入
+	if m.focus != FocusEditor {
+		m.suggestion = ""
+		return
+	}
 
-	// 只发送当前行做上下文 (MVP 简化)
+	// 只发送当前行光标前的部分做上下文 (MVP 简化)
 	curTab := m.tabs[m.activeTab]
 	currPane := curTab.Panes[curTab.ActivePane]
 	if currPane.CursorY >= len(currPane.Lines) { return }
-	currentLine := currPane.Lines[currPane.CursorY]
+	runes := []rune(currPane.Lines[currPane.CursorY])
+	cx := currPane.CursorX
+	if cx > len(runes) {
+		cx = len(runes)
+	}
+	prefix := string(runes[:cx])
 	
-	// 如果行为空，不预测
-	if strings.TrimSpace(currentLine) == "" {
+	// 如果光标前为空，不预测
+	if strings.TrimSpace(prefix) == "" {
 		m.suggestion = ""
 		return 
 	}
 
 	// 调用 WASM "predict_code"
-	exitCode, output, err := m.plugin.Call("predict_code", []byte(currentLine))
+	exitCode, output, err := m.plugin.Call("predict_code", []byte(prefix))
 	if err != nil || exitCode != 0 {
 		// 忽略预测错误，不显示建议
 		return
 	}
 
 	prediction := string(output)
+	// Ghost Text 只显示在当前行，多行建议只取第一行
+	if idx := strings.IndexByte(prediction, '\n'); idx >= 0 {
+		prediction = prediction[:idx]
+	}
 	if prediction != "" {
 		m.suggestion = prediction
 		// 调试信息 (可选)
@@ -3469,6 +3521,13 @@ func (m *Model) predictCode() {
 	}
 }
 
+// acceptSuggestion 将 AI 建议 (Ghost Text) 插入到光标位置
+func (m *Model) acceptSuggestion(p *EditorPane, suggestion string) {
+	for _, ch := range suggestion {
+		m.insertChar(p, ch)
+	}
+}
+
 // =============================================================================
 // 语法高亮
 // =============================================================================
@@ -4248,7 +4307,19 @@ func (m Model) renderPane(p *EditorPane, width, height int, isActive bool) strin
 				cx = len(runes)
 			}
 
-			if cx == len(runes) {
+			// Ghost Text: AI 建议以暗色显示在插入位置 (CursorX)，
+			// 光标块落在建议的第一个字符上，与 Tab 接受后的插入结果一致
+			var ghost []rune
+			if m.mode == InsertMode && m.focus == FocusEditor {
+				ghost = []rune(m.suggestion)
+			}
+
+			if len(ghost) > 0 {
+				before := string(runes[:cx])
+				cursorChar := "\x1b[7m" + string(ghost[0]) + "\x1b[0m"
+				rest := suggestionStyle.Render(string(ghost[1:]))
+				lineContent = before + cursorChar + rest + string(runes[cx:])
+			} else if cx == len(runes) {
 				// Cursor at EOL
 				lineContent = string(runes) + "\x1b[7m \x1b[0m"
 			} else {
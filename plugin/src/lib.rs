@@ -22,14 +22,32 @@ const COMMAND_PREFIX: &str = ";;";
 /// - 处理后的缓冲区文本（";;" 后的内容已被转换为大写）
 #[plugin_fn]
 pub fn process_command(input: String) -> FnResult<String> {
-    // 按行处理缓冲区
-    let processed_lines: Vec<String> = input
-        .lines()
-        .map(|line| process_single_line(line))
-        .collect();
+    Ok(process_buffer(&input))
+}
 
-    // 用换行符重新连接所有行
-    Ok(processed_lines.join("\n"))
+/// 按行处理整个缓冲区
+///
+/// 每行原有的换行符（"\n" 或 "\r\n"）以及末尾换行都原样保留，
+/// 保证 Host 往返一次后缓冲区不会被改写。
+fn process_buffer(input: &str) -> String {
+    input
+        .split_inclusive('\n')
+        .map(|segment| {
+            let (line, ending) = split_line_ending(segment);
+            process_single_line(line) + ending
+        })
+        .collect()
+}
+
+/// 拆分行内容与行尾换行符
+fn split_line_ending(segment: &str) -> (&str, &str) {
+    if let Some(line) = segment.strip_suffix("\r\n") {
+        (line, "\r\n")
+    } else if let Some(line) = segment.strip_suffix('\n') {
+        (line, "\n")
+    } else {
+        (segment, "")
+    }
 }
 
 /// 处理单行文本
@@ -79,4 +97,20 @@ mod tests {
         assert_eq!(process_single_line("normal line"), "normal line");
         assert_eq!(process_single_line(""), "");
     }
+
+    #[test]
+    fn test_buffer_preserves_line_endings() {
+        assert_eq!(process_buffer(";;a\nb\n"), ";;A\nb\n");
+        assert_eq!(process_buffer(";;a\r\nb\r\n;;c"), ";;A\r\nb\r\n;;C");
+        assert_eq!(process_buffer("a\n\n"), "a\n\n");
+        // Host 加载时已去掉 "\r"，以 "\n" 拼接各行发送；
+        // 关键是末尾换行不能丢失，否则缓冲区最后的空行会被吞掉
+        assert_eq!(process_buffer(";;a\nb\n\n"), ";;A\nb\n\n");
+
+        // split_line_ending 对 "\r\n" 和单独的 "\r" 的通用处理
+        assert_eq!(process_buffer(";;a\r\n"), ";;A\r\n");
+        assert_eq!(process_buffer(";;a\r\nb\r\n;;c\r"), ";;A\r\nb\r\n;;C\r");
+        assert_eq!(process_buffer(";;a\r"), ";;A\r");
+        assert_eq!(process_buffer(""), "");
+    }
 }